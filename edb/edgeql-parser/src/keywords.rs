@@ -92,7 +92,6 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "write",
 ];
 
-
 pub const FUTURE_RESERVED_KEYWORDS: &[&str] = &[
     // Keep in sync with `tokenizer::is_keyword`
     "analyze",
@@ -190,3 +189,551 @@ pub const CURRENT_RESERVED_KEYWORDS: &[&str] = &[
     "with",
     // Keep in sync with `tokenizer::is_keyword`
 ];
+
+/// The largest edit distance a near-miss is still allowed to suggest at,
+/// before falling back to "no suggestion".
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+fn all_keywords() -> impl Iterator<Item = &'static str> {
+    UNRESERVED_KEYWORDS
+        .iter()
+        .chain(CURRENT_RESERVED_KEYWORDS.iter())
+        .chain(FUTURE_RESERVED_KEYWORDS.iter())
+        .copied()
+}
+
+/// All keywords, sorted by length, so lookups can binary-search straight to
+/// the length window that a bounded edit distance can possibly reach.
+fn keywords_by_length() -> &'static [(usize, &'static str)] {
+    static TABLE: std::sync::OnceLock<Vec<(usize, &'static str)>> = std::sync::OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table: Vec<(usize, &'static str)> =
+            all_keywords().map(|kw| (kw.len(), kw)).collect();
+        table.sort_by_key(|&(len, _)| len);
+        table
+    })
+}
+
+/// Bounded Damerau-Levenshtein distance between `a` and `b`, or `None` if it
+/// exceeds `max_dist`.
+///
+/// Bails out of a candidate early once every cell in the current DP row is
+/// already past `max_dist`, since no later row can bring the distance back
+/// down.
+fn bounded_edit_distance(a: &[u8], b: &[u8], max_dist: usize) -> Option<usize> {
+    let (m, n) = (a.len(), b.len());
+    if m.abs_diff(n) > max_dist {
+        return None;
+    }
+    let mut d = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0].iter_mut().enumerate().for_each(|(j, c)| *c = j);
+    for i in 1..=m {
+        let mut row_min = d[i][0];
+        for j in 1..=n {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut val = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                val = val.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = val;
+            row_min = row_min.min(val);
+        }
+        if row_min > max_dist {
+            return None;
+        }
+    }
+    let dist = d[m][n];
+    (dist <= max_dist).then_some(dist)
+}
+
+/// Suggests the closest keyword to `word`, for use in "did you mean ...?"
+/// parser/tokenizer diagnostics.
+///
+/// Returns `None` if `word` isn't a near-miss for any keyword in
+/// `UNRESERVED_KEYWORDS`, `CURRENT_RESERVED_KEYWORDS` or
+/// `FUTURE_RESERVED_KEYWORDS` within a bounded Damerau-Levenshtein distance
+/// (at most `MAX_SUGGESTION_DISTANCE`, tightening to `word.len() / 3` for
+/// short words so two-letter typos don't match half the keyword list). Ties
+/// are broken by shortest keyword, then alphabetically.
+pub fn suggest_keyword(word: &str) -> Option<&'static str> {
+    let word = word.to_ascii_lowercase();
+    let wb = word.as_bytes();
+    let m = wb.len();
+    let threshold = MAX_SUGGESTION_DISTANCE.min(m / 3);
+    if threshold == 0 {
+        return None;
+    }
+    let table = keywords_by_length();
+    let lo = m.saturating_sub(MAX_SUGGESTION_DISTANCE);
+    let hi = m + MAX_SUGGESTION_DISTANCE;
+    let start = table.partition_point(|&(len, _)| len < lo);
+
+    let mut best: Option<(usize, &'static str)> = None;
+    for &(len, kw) in &table[start..] {
+        if len > hi {
+            break;
+        }
+        if kw.as_bytes() == wb {
+            continue;
+        }
+        let Some(dist) = bounded_edit_distance(wb, kw.as_bytes(), threshold) else {
+            continue;
+        };
+        best = Some(match best {
+            Some((best_dist, best_kw))
+                if best_dist < dist
+                    || (best_dist == dist && (best_kw.len(), best_kw) <= (kw.len(), kw)) =>
+            {
+                (best_dist, best_kw)
+            }
+            _ => (dist, kw),
+        });
+    }
+    best.map(|(_, kw)| kw)
+}
+
+#[cfg(test)]
+mod suggest_keyword_tests {
+    use super::*;
+
+    #[test]
+    fn suggests_single_missing_letter_typo() {
+        assert_eq!(suggest_keyword("slect"), Some("select"));
+    }
+
+    #[test]
+    fn suggests_swapped_letters_typo() {
+        assert_eq!(suggest_keyword("fitler"), Some("filter"));
+    }
+
+    #[test]
+    fn suggests_transposition_as_distance_one() {
+        // "odrer" is "order" with positions 2 and 3 (the "d" and "r")
+        // swapped, so it's distance 1 only via the transposition case.
+        assert_eq!(suggest_keyword("odrer"), Some("order"));
+    }
+
+    #[test]
+    fn no_suggestion_for_short_words() {
+        // threshold == MAX_SUGGESTION_DISTANCE.min(m / 3) == 0 for m < 3
+        assert_eq!(suggest_keyword("by"), None);
+    }
+
+    #[test]
+    fn no_suggestion_beyond_threshold() {
+        assert_eq!(suggest_keyword("xyzzyplugh"), None);
+    }
+
+    #[test]
+    fn breaks_ties_by_shortest_keyword() {
+        // "all", "as" and "asc" are all distance 1 from "asl"; "as" wins
+        // because it's the shortest.
+        assert_eq!(suggest_keyword("asl"), Some("as"));
+    }
+
+    #[test]
+    fn breaks_ties_alphabetically_when_same_length() {
+        // "after" and "alter" are both distance 1 from "aater" and the same
+        // length; "after" wins alphabetically.
+        assert_eq!(suggest_keyword("aater"), Some("after"));
+    }
+}
+
+/// Syntactic role of a keyword, independent of its reservation status.
+///
+/// This is the single source of truth editor integrations (VS Code,
+/// tree-sitter grammars, ...) should generate their highlighting rules from,
+/// instead of hand-maintaining a second keyword list that drifts out of
+/// sync with this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordCategory {
+    /// Statement-introducing verbs: `select`, `insert`, `create`, ...
+    Statement,
+    /// Clause keywords that modify a statement: `filter`, `order`, `limit`, ...
+    Clause,
+    /// Schema, type and storage related keywords: `scalar`, `type`, `property`, ...
+    TypeModifier,
+    /// Logical, comparison and membership operators: `and`, `or`, `like`, ...
+    Operator,
+    /// Literal constants: `true`, `false`, `empty`.
+    Constant,
+    /// A keyword that doesn't fit a more specific category above.
+    Other,
+}
+
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "select",
+    "insert",
+    "update",
+    "delete",
+    "create",
+    "alter",
+    "drop",
+    "describe",
+    "configure",
+    "declare",
+    "start",
+    "commit",
+    "rollback",
+    "abort",
+    "release",
+    "reset",
+    "set",
+    "populate",
+];
+
+const CLAUSE_KEYWORDS: &[&str] = &[
+    "filter", "order", "limit", "offset", "group", "for", "with", "using", "on", "into", "from",
+    "else", "then", "when",
+];
+
+const TYPE_MODIFIER_KEYWORDS: &[&str] = &[
+    "scalar",
+    "type",
+    "property",
+    "link",
+    "object",
+    "abstract",
+    "final",
+    "required",
+    "optional",
+    "multi",
+    "single",
+    "constraint",
+    "index",
+    "function",
+    "module",
+    "cast",
+    "annotation",
+];
+
+const OPERATOR_KEYWORDS: &[&str] = &[
+    "and", "or", "not", "like", "ilike", "is", "in", "exists", "distinct", "union", "typeof",
+];
+
+const CONSTANT_KEYWORDS: &[&str] = &["true", "false", "empty"];
+
+/// Classifies `keyword`'s syntactic role, or `None` if it isn't a keyword at
+/// all (neither reserved nor unreserved).
+pub fn classify(keyword: &str) -> Option<KeywordCategory> {
+    let keyword = keyword.to_ascii_lowercase();
+    let keyword = keyword.as_str();
+    if STATEMENT_KEYWORDS.contains(&keyword) {
+        Some(KeywordCategory::Statement)
+    } else if CLAUSE_KEYWORDS.contains(&keyword) {
+        Some(KeywordCategory::Clause)
+    } else if TYPE_MODIFIER_KEYWORDS.contains(&keyword) {
+        Some(KeywordCategory::TypeModifier)
+    } else if OPERATOR_KEYWORDS.contains(&keyword) {
+        Some(KeywordCategory::Operator)
+    } else if CONSTANT_KEYWORDS.contains(&keyword) {
+        Some(KeywordCategory::Constant)
+    } else if all_keywords().any(|kw| kw == keyword) {
+        Some(KeywordCategory::Other)
+    } else {
+        None
+    }
+}
+
+/// The TextMate-style grammar scope editors expect for a given category,
+/// e.g. for a VS Code `tmLanguage` or tree-sitter `highlights.scm` file.
+pub fn textmate_scope(category: KeywordCategory) -> &'static str {
+    match category {
+        KeywordCategory::Statement | KeywordCategory::Clause => "keyword.control",
+        KeywordCategory::TypeModifier => "storage.type",
+        KeywordCategory::Operator => "keyword.operator",
+        KeywordCategory::Constant => "constant.language",
+        KeywordCategory::Other => "keyword.other",
+    }
+}
+
+/// Emits every keyword's TextMate scope, keyed by keyword, so a grammar file
+/// can be generated straight from this crate instead of hand-maintained.
+pub fn grammar_scope_map() -> std::collections::BTreeMap<&'static str, &'static str> {
+    all_keywords()
+        .filter_map(|kw| classify(kw).map(|category| (kw, textmate_scope(category))))
+        .collect()
+}
+
+#[cfg(test)]
+mod classify_tests {
+    use super::*;
+
+    #[test]
+    fn classifies_representative_keyword_per_category() {
+        assert_eq!(classify("select"), Some(KeywordCategory::Statement));
+        assert_eq!(classify("filter"), Some(KeywordCategory::Clause));
+        assert_eq!(classify("scalar"), Some(KeywordCategory::TypeModifier));
+        assert_eq!(classify("and"), Some(KeywordCategory::Operator));
+        assert_eq!(classify("true"), Some(KeywordCategory::Constant));
+    }
+
+    #[test]
+    fn classifies_uncategorized_keyword_as_other() {
+        // A keyword that exists but isn't in any of the specific category
+        // tables, e.g. a DDL noun like "migration".
+        assert_eq!(classify("migration"), Some(KeywordCategory::Other));
+    }
+
+    #[test]
+    fn classifies_case_insensitively() {
+        assert_eq!(classify("SELECT"), Some(KeywordCategory::Statement));
+    }
+
+    #[test]
+    fn non_keyword_classifies_to_none() {
+        assert_eq!(classify("not_a_keyword"), None);
+    }
+
+    #[test]
+    fn every_keyword_classifies_to_something() {
+        for kw in all_keywords() {
+            assert!(classify(kw).is_some(), "{kw} should classify to Some(_)");
+        }
+    }
+
+    #[test]
+    fn category_tables_classify_to_their_own_category() {
+        // Catches the `Other` catch-all silently absorbing a keyword that
+        // belongs in one of the specific tables above.
+        for &kw in STATEMENT_KEYWORDS {
+            assert_eq!(classify(kw), Some(KeywordCategory::Statement), "{kw}");
+        }
+        for &kw in CLAUSE_KEYWORDS {
+            assert_eq!(classify(kw), Some(KeywordCategory::Clause), "{kw}");
+        }
+        for &kw in TYPE_MODIFIER_KEYWORDS {
+            assert_eq!(classify(kw), Some(KeywordCategory::TypeModifier), "{kw}");
+        }
+        for &kw in OPERATOR_KEYWORDS {
+            assert_eq!(classify(kw), Some(KeywordCategory::Operator), "{kw}");
+        }
+        for &kw in CONSTANT_KEYWORDS {
+            assert_eq!(classify(kw), Some(KeywordCategory::Constant), "{kw}");
+        }
+    }
+
+    #[test]
+    fn grammar_scope_map_covers_every_keyword() {
+        let map = grammar_scope_map();
+        for kw in all_keywords() {
+            assert!(map.contains_key(kw), "{kw} missing from grammar_scope_map");
+        }
+        assert_eq!(map["select"], "keyword.control");
+        assert_eq!(map["scalar"], "storage.type");
+        assert_eq!(map["and"], "keyword.operator");
+        assert_eq!(map["true"], "constant.language");
+    }
+}
+
+/// Each `FUTURE_RESERVED_KEYWORDS` word paired with the `(major, minor)`
+/// server version it graduates to a reserved keyword in.
+///
+/// Keep in sync with `FUTURE_RESERVED_KEYWORDS` above, and update the
+/// version here once a graduation actually ships in a release.
+const FUTURE_RESERVED_GRADUATION: &[(&str, (u32, u32))] = &[
+    ("analyze", (3, 0)),
+    ("anyarray", (3, 0)),
+    ("begin", (3, 0)),
+    ("case", (3, 0)),
+    ("check", (3, 0)),
+    ("deallocate", (3, 0)),
+    ("discard", (3, 0)),
+    ("do", (3, 0)),
+    ("end", (3, 0)),
+    ("execute", (3, 0)),
+    ("explain", (3, 0)),
+    ("fetch", (4, 0)),
+    ("get", (4, 0)),
+    ("global", (4, 0)),
+    ("grant", (4, 0)),
+    ("import", (4, 0)),
+    ("listen", (4, 0)),
+    ("load", (4, 0)),
+    ("lock", (4, 0)),
+    ("match", (4, 0)),
+    ("move", (4, 0)),
+    ("notify", (5, 0)),
+    ("prepare", (5, 0)),
+    ("partition", (5, 0)),
+    ("policy", (5, 0)),
+    ("raise", (5, 0)),
+    ("refresh", (5, 0)),
+    ("reindex", (5, 0)),
+    ("revoke", (5, 0)),
+    ("over", (5, 0)),
+    ("when", (5, 0)),
+    ("window", (5, 0)),
+];
+
+/// A word's reservation state within a specific [`KeywordSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordKind {
+    Unreserved,
+    Reserved,
+    /// Not a keyword for this version at all — a plain identifier.
+    Identifier,
+}
+
+/// The keyword universe as it applies to a specific targeted EdgeDB
+/// server/schema version.
+///
+/// `FUTURE_RESERVED_KEYWORDS` are reserved only once the target version
+/// reaches the version they graduate in ([`FUTURE_RESERVED_GRADUATION`]); a
+/// query written against an older server can therefore still use them as
+/// plain identifiers.
+#[derive(Debug, Clone)]
+pub struct KeywordSet {
+    reserved: Vec<&'static str>,
+}
+
+impl KeywordSet {
+    /// The keyword set for the most recent EdgeDB release: every
+    /// future-reserved word has already graduated. This preserves today's
+    /// behavior for callers that don't target a specific version.
+    pub fn latest() -> KeywordSet {
+        KeywordSet::for_version(u32::MAX, u32::MAX)
+    }
+
+    /// Builds the keyword set applicable to the given target server version.
+    pub fn for_version(major: u32, minor: u32) -> KeywordSet {
+        let mut reserved = CURRENT_RESERVED_KEYWORDS.to_vec();
+        reserved.extend(
+            FUTURE_RESERVED_GRADUATION
+                .iter()
+                .filter(|&&(_, graduates_in)| (major, minor) >= graduates_in)
+                .map(|&(kw, _)| kw),
+        );
+        KeywordSet { reserved }
+    }
+
+    /// Classifies `word` as unreserved, reserved, or a plain identifier for
+    /// this keyword set.
+    pub fn kind(&self, word: &str) -> KeywordKind {
+        let word = word.to_ascii_lowercase();
+        if self.reserved.contains(&word.as_str()) {
+            KeywordKind::Reserved
+        } else if UNRESERVED_KEYWORDS.contains(&word.as_str()) {
+            KeywordKind::Unreserved
+        } else {
+            KeywordKind::Identifier
+        }
+    }
+
+    /// Whether `word` is a keyword (reserved or unreserved) for this set.
+    ///
+    /// Parameterized equivalent of `tokenizer::is_keyword`, which should
+    /// route through `KeywordSet::latest().is_keyword(word)` to keep
+    /// today's un-versioned behavior.
+    pub fn is_keyword(&self, word: &str) -> bool {
+        !matches!(self.kind(word), KeywordKind::Identifier)
+    }
+}
+
+impl Default for KeywordSet {
+    fn default() -> KeywordSet {
+        KeywordSet::latest()
+    }
+}
+
+#[cfg(test)]
+mod keyword_set_tests {
+    use super::*;
+
+    #[test]
+    fn future_reserved_graduation_matches_future_reserved_keywords() {
+        // FUTURE_RESERVED_GRADUATION is hand-maintained "in sync with
+        // FUTURE_RESERVED_KEYWORDS" per its doc comment; nothing else
+        // enforces that, so check the two lists cover the same set.
+        let keywords: std::collections::BTreeSet<_> =
+            FUTURE_RESERVED_KEYWORDS.iter().copied().collect();
+        let graduation: std::collections::BTreeSet<_> = FUTURE_RESERVED_GRADUATION
+            .iter()
+            .map(|&(kw, _)| kw)
+            .collect();
+        assert_eq!(
+            keywords, graduation,
+            "FUTURE_RESERVED_KEYWORDS and FUTURE_RESERVED_GRADUATION have drifted out of sync"
+        );
+    }
+
+    #[test]
+    fn future_reserved_word_is_identifier_below_graduation_version() {
+        // "fetch" graduates in (4, 0).
+        assert_eq!(
+            KeywordSet::for_version(3, 0).kind("fetch"),
+            KeywordKind::Identifier
+        );
+        assert_eq!(
+            KeywordSet::for_version(3, 9).kind("fetch"),
+            KeywordKind::Identifier
+        );
+    }
+
+    #[test]
+    fn future_reserved_word_is_reserved_at_and_above_graduation_version() {
+        assert_eq!(
+            KeywordSet::for_version(4, 0).kind("fetch"),
+            KeywordKind::Reserved
+        );
+        assert_eq!(
+            KeywordSet::for_version(4, 1).kind("fetch"),
+            KeywordKind::Reserved
+        );
+        assert_eq!(
+            KeywordSet::for_version(5, 0).kind("fetch"),
+            KeywordKind::Reserved
+        );
+    }
+
+    #[test]
+    fn currently_reserved_word_is_reserved_for_every_version() {
+        assert_eq!(
+            KeywordSet::for_version(0, 0).kind("select"),
+            KeywordKind::Reserved
+        );
+    }
+
+    #[test]
+    fn unreserved_word_is_unreserved_for_every_version() {
+        assert_eq!(
+            KeywordSet::for_version(0, 0).kind("scalar"),
+            KeywordKind::Unreserved
+        );
+        assert_eq!(KeywordSet::latest().kind("scalar"), KeywordKind::Unreserved);
+    }
+
+    #[test]
+    fn non_keyword_is_identifier() {
+        assert_eq!(
+            KeywordSet::for_version(0, 0).kind("my_column"),
+            KeywordKind::Identifier
+        );
+    }
+
+    #[test]
+    fn latest_reserves_every_future_reserved_word() {
+        let latest = KeywordSet::latest();
+        for &(kw, _) in FUTURE_RESERVED_GRADUATION {
+            assert_eq!(
+                latest.kind(kw),
+                KeywordKind::Reserved,
+                "{kw} should be reserved in latest()"
+            );
+        }
+    }
+
+    #[test]
+    fn is_keyword_matches_kind() {
+        let set = KeywordSet::for_version(3, 0);
+        assert!(set.is_keyword("select"));
+        assert!(set.is_keyword("scalar"));
+        assert!(!set.is_keyword("fetch"));
+        assert!(!set.is_keyword("my_column"));
+    }
+}